@@ -33,6 +33,43 @@ impl ClientPluginGroup {
             lightyear: ClientPlugin::new(plugin_config),
         }
     }
+
+    /// Build a `ClientPluginGroup` connected over lightyear's Steam socket transport. Returns
+    /// `SteamworksHandles` alongside it since `Plugin`s must be `Send + Sync` and Steamworks'
+    /// handles aren't — the caller must register both as non-send resources before `App::run`.
+    pub(crate) fn new_steam(steam_config: SteamConfig) -> (ClientPluginGroup, SteamworksHandles) {
+        let (steamworks_client, steamworks_single) =
+            steamworks::Client::init_app(steam_config.app_id)
+                .expect("failed to initialize the Steamworks client");
+        let client_id = steamworks_client.user().steam_id().raw();
+        let io_config = IoConfig::from_transport(TransportConfig::Steamworks {
+            app_id: steam_config.app_id,
+        });
+        let net_config = NetConfig::Netcode {
+            auth: Authentication::Manual {
+                server_addr: steam_config.server_addr,
+                client_id,
+                private_key: Key::default(),
+                protocol_id: 0,
+            },
+            config: NetcodeConfig::default(),
+            io: io_config,
+        };
+        (
+            Self::new(net_config),
+            SteamworksHandles {
+                client: steamworks_client,
+                single: steamworks_single,
+            },
+        )
+    }
+}
+
+/// The Steamworks handles created by `ClientPluginGroup::new_steam`. See that function's doc
+/// comment for why these are returned separately instead of owned by the plugin group.
+pub struct SteamworksHandles {
+    pub client: steamworks::Client,
+    pub single: steamworks::SingleClient,
 }
 
 pub struct SteamConfig {
@@ -62,34 +99,283 @@ pub struct ExampleClientPlugin;
 
 impl Plugin for ExampleClientPlugin {
     fn build(&self, app: &mut App) {
+        app.init_state::<ClientConnectionState>();
+        app.init_resource::<ReconnectBackoff>();
+        app.init_resource::<ReconnectTimer>();
         app.add_systems(Startup, init);
+        app.add_systems(
+            OnEnter(ClientConnectionState::Connecting),
+            connect_on_enter_connecting,
+        );
         app.add_systems(PreUpdate, spawn_cursor.after(MainSet::ReceiveFlush));
         // Inputs need to be buffered in the `FixedPreUpdate` schedule
         app.add_systems(
             FixedPreUpdate,
-            buffer_input.in_set(InputSystemSet::BufferInputs),
+            buffer_input
+                .in_set(InputSystemSet::BufferInputs)
+                .run_if(in_state(ClientConnectionState::Connected)),
         );
         // all actions related-system that can be rolled back should be in the `FixedUpdate` schedule
-        app.add_systems(FixedUpdate, (player_movement, delete_player));
+        app.add_systems(
+            FixedUpdate,
+            (
+                player_movement.run_if(in_state(ClientConnectionState::Connected)),
+                delete_player,
+            ),
+        );
         app.add_systems(
             Update,
             (
-                cursor_movement,
+                cursor_movement.run_if(in_state(ClientConnectionState::Connected)),
                 receive_message,
                 send_message,
                 spawn_player,
                 handle_predicted_spawn,
                 handle_interpolated_spawn,
                 touch_event_system,
+                touch_action_buttons,
+                update_connection_status_text,
+                handle_connect_event,
+                handle_disconnect_event,
+                tick_reconnect_timer.run_if(in_state(ClientConnectionState::Disconnected)),
+                pump_steamworks_callbacks,
             ),
         );
+        app.init_resource::<TouchInputState>();
+        app.add_systems(Startup, spawn_touch_controls);
+        app.init_resource::<PredictionGroup>();
+        app.add_systems(PreUpdate, track_prediction_group.after(MainSet::ReceiveFlush));
+        app.init_resource::<InterpolationGroup>();
+        app.add_systems(PreUpdate, track_interpolation_group.after(MainSet::ReceiveFlush));
+        app.init_resource::<PendingOutbox>();
+        app.add_systems(Update, drain_pending_outbox);
+    }
+}
+
+/// Connection lifecycle of the client, driven by lightyear's `ConnectEvent`/`DisconnectEvent`.
+///
+/// Input/movement systems (`buffer_input`, `player_movement`, `cursor_movement`) only run
+/// while `Connected`; entering `Connecting` triggers `client.connect()`.
+#[derive(States, Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub(crate) enum ClientConnectionState {
+    #[default]
+    Disconnected,
+    Connecting,
+    Connected,
+}
+
+/// How long to wait after a `DisconnectEvent` before automatically retrying the connection.
+#[derive(Resource)]
+pub(crate) struct ReconnectBackoff(pub Duration);
+
+impl Default for ReconnectBackoff {
+    fn default() -> Self {
+        Self(Duration::from_secs(2))
+    }
+}
+
+/// Counts down `ReconnectBackoff` while `Disconnected`, then transitions back to `Connecting`.
+#[derive(Resource, Default)]
+pub(crate) struct ReconnectTimer(Option<Timer>);
+
+/// Marker for the on-screen connection status text.
+#[derive(Component)]
+pub(crate) struct ConnectionStatusText;
+
+// Trigger the actual connection attempt whenever we enter `Connecting`, whether that's the
+// initial connection or an automatic reconnect after a drop.
+fn connect_on_enter_connecting(mut client: ResMut<ClientConnection>) {
+    let _ = client.connect();
+}
+
+// Steamworks requires its callbacks pumped every frame, or the session/socket silently stop
+// working. A no-op when the caller didn't register `SteamworksHandles` (i.e. not using the
+// Steam transport), since the non-send resource is simply absent.
+fn pump_steamworks_callbacks(single: Option<NonSend<steamworks::SingleClient>>) {
+    if let Some(single) = single {
+        single.run_callbacks();
+    }
+}
+
+// Reflect the current `ClientConnectionState` in the on-screen status text.
+fn update_connection_status_text(
+    state: Res<State<ClientConnectionState>>,
+    mut text_query: Query<&mut Text, With<ConnectionStatusText>>,
+) {
+    if !state.is_changed() {
+        return;
+    }
+    let label = match state.get() {
+        ClientConnectionState::Disconnected => "Disconnected",
+        ClientConnectionState::Connecting => "Connecting...",
+        ClientConnectionState::Connected => "Connected",
+    };
+    for mut text in text_query.iter_mut() {
+        text.sections[0].value = label.to_string();
+    }
+}
+
+fn handle_connect_event(
+    mut connect_events: EventReader<ConnectEvent>,
+    mut next_state: ResMut<NextState<ClientConnectionState>>,
+) {
+    if connect_events.read().next().is_some() {
+        next_state.set(ClientConnectionState::Connected);
+    }
+}
+
+// On disconnect, fall back to `Disconnected` and arm the reconnect timer so the client
+// transparently re-establishes the session after `ReconnectBackoff`.
+fn handle_disconnect_event(
+    mut disconnect_events: EventReader<DisconnectEvent>,
+    mut next_state: ResMut<NextState<ClientConnectionState>>,
+    backoff: Res<ReconnectBackoff>,
+    mut reconnect_timer: ResMut<ReconnectTimer>,
+) {
+    if disconnect_events.read().next().is_some() {
+        next_state.set(ClientConnectionState::Disconnected);
+        reconnect_timer.0 = Some(Timer::new(backoff.0, TimerMode::Once));
+    }
+}
+
+fn tick_reconnect_timer(
+    time: Res<Time>,
+    mut reconnect_timer: ResMut<ReconnectTimer>,
+    mut next_state: ResMut<NextState<ClientConnectionState>>,
+) {
+    let Some(timer) = reconnect_timer.0.as_mut() else {
+        return;
+    };
+    if timer.tick(time.delta()).just_finished() {
+        reconnect_timer.0 = None;
+        next_state.set(ClientConnectionState::Connecting);
     }
 }
 
+/// Radius (in logical pixels) the virtual joystick knob can travel from its origin.
+const JOYSTICK_RADIUS: f32 = 60.0;
+/// Normalized knob offsets below this magnitude are treated as centered (no input).
+const JOYSTICK_DEAD_ZONE: f32 = 0.2;
+
+/// Marker for the virtual joystick's base circle.
+#[derive(Component)]
+pub(crate) struct JoystickBase;
+
+/// Marker for the virtual joystick's draggable knob.
+#[derive(Component)]
+pub(crate) struct JoystickKnob;
+
+/// Marker for the on-screen spawn button.
+#[derive(Component)]
+pub(crate) struct TouchSpawnButton;
+
+/// Marker for the on-screen delete button.
+#[derive(Component)]
+pub(crate) struct TouchDeleteButton;
+
+/// Direction (and latched spawn/delete flags) derived from the on-screen touch controls; merged by `buffer_input`.
+#[derive(Resource, Default)]
+pub(crate) struct TouchInputState {
+    /// The touch `id` currently driving the joystick, and the screen-space origin (the
+    /// base circle's center) that knob offsets are measured from.
+    active_touch: Option<(u64, Vec2)>,
+    direction: Direction,
+    spawn: bool,
+    delete: bool,
+}
+
+// Spawn the virtual joystick (base + knob) and the spawn/delete action buttons.
+pub(crate) fn spawn_touch_controls(mut commands: Commands) {
+    commands
+        .spawn((
+            JoystickBase,
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(40.0),
+                    bottom: Val::Px(40.0),
+                    width: Val::Px(JOYSTICK_RADIUS * 2.0),
+                    height: Val::Px(JOYSTICK_RADIUS * 2.0),
+                    ..default()
+                },
+                background_color: Color::rgba(1.0, 1.0, 1.0, 0.2).into(),
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                JoystickKnob,
+                NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(JOYSTICK_RADIUS * 0.5),
+                        top: Val::Px(JOYSTICK_RADIUS * 0.5),
+                        width: Val::Px(JOYSTICK_RADIUS),
+                        height: Val::Px(JOYSTICK_RADIUS),
+                        ..default()
+                    },
+                    background_color: Color::rgba(1.0, 1.0, 1.0, 0.5).into(),
+                    ..default()
+                },
+            ));
+        });
+
+    commands.spawn((
+        TouchSpawnButton,
+        ButtonBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                right: Val::Px(110.0),
+                bottom: Val::Px(40.0),
+                width: Val::Px(60.0),
+                height: Val::Px(60.0),
+                ..default()
+            },
+            background_color: Color::rgba(0.2, 0.8, 0.2, 0.5).into(),
+            ..default()
+        },
+    ));
+    commands.spawn((
+        TouchDeleteButton,
+        ButtonBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                right: Val::Px(40.0),
+                bottom: Val::Px(40.0),
+                width: Val::Px(60.0),
+                height: Val::Px(60.0),
+                ..default()
+            },
+            background_color: Color::rgba(0.8, 0.2, 0.2, 0.5).into(),
+            ..default()
+        },
+    ));
+}
+
 // Startup system for the client
-pub(crate) fn init(mut commands: Commands, mut client: ResMut<ClientConnection>) {
+pub(crate) fn init(
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<ClientConnectionState>>,
+) {
     commands.spawn(Camera2dBundle::default());
-    let _ = client.connect();
+    commands.spawn((
+        ConnectionStatusText,
+        TextBundle::from_section(
+            "Disconnected",
+            TextStyle {
+                font_size: 20.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            left: Val::Px(10.0),
+            ..default()
+        }),
+    ));
+    next_state.set(ClientConnectionState::Connecting);
 }
 
 pub(crate) fn spawn_cursor(mut commands: Commands, metadata: Res<GlobalMetadata>) {
@@ -120,14 +406,12 @@ pub(crate) fn buffer_input(
     tick_manager: Res<TickManager>,
     mut connection_manager: ResMut<ClientConnectionManager>,
     keypress: Res<ButtonInput<KeyCode>>,
+    mut touch_state: ResMut<TouchInputState>,
+    metadata: Res<GlobalMetadata>,
+    mut outbox: ResMut<PendingOutbox>,
 ) {
     let tick = tick_manager.tick();
-    let mut direction = Direction {
-        up: false,
-        down: false,
-        left: false,
-        right: false,
-    };
+    let mut direction = touch_state.direction;
     if keypress.pressed(KeyCode::KeyW) || keypress.pressed(KeyCode::ArrowUp) {
         direction.up = true;
     }
@@ -140,24 +424,176 @@ pub(crate) fn buffer_input(
     if keypress.pressed(KeyCode::KeyD) || keypress.pressed(KeyCode::ArrowRight) {
         direction.right = true;
     }
-    if !direction.is_none() {
-        return connection_manager.add_input(Inputs::Direction(direction), tick);
+
+    let mut actions = InputActions::empty();
+    if keypress.pressed(KeyCode::KeyK) || touch_state.delete {
+        actions.insert(InputActions::DELETE);
+    }
+    if keypress.pressed(KeyCode::Space) || touch_state.spawn {
+        actions.insert(InputActions::SPAWN);
     }
-    if keypress.pressed(KeyCode::KeyK) {
-        // currently, directions is an enum and we can only add one input per tick
-        return connection_manager.add_input(Inputs::Delete, tick);
+    touch_state.delete = false;
+    touch_state.spawn = false;
+
+    // The connection can reach `Connected` before `GlobalMetadata::client_id` syncs; queue
+    // spawn/delete presses made in that window instead of dropping them, and replay them in
+    // order once `drain_pending_outbox` sees the sync complete.
+    if metadata.client_id.is_none() {
+        if !actions.is_empty() {
+            outbox.actions.push_back(actions);
+        }
+        return;
     }
-    if keypress.pressed(KeyCode::Space) {
-        return connection_manager.add_input(Inputs::Spawn, tick);
+
+    connection_manager.add_input(Inputs { direction, actions }, tick);
+}
+
+/// Identifies the `PredictionGroup`/`InterpolationGroup` an entity belongs to, layered on top
+/// of lightyear's replication group so related entities (e.g. a player and the objects it's
+/// currently pushing) are predicted/interpolated together rather than in isolation.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct GroupId(pub u64);
+
+impl GroupId {
+    /// A group containing only `confirmed`, used as a fallback for entities that don't carry
+    /// an explicit `GroupId` component (e.g. nothing in this example attaches one yet). This
+    /// keeps single-entity prediction working exactly as before groups existed, instead of
+    /// silently registering nothing.
+    fn singleton(confirmed: Entity) -> Self {
+        GroupId(confirmed.to_bits())
     }
-    return connection_manager.add_input(Inputs::None, tick);
 }
 
-// The client input only gets applied to predicted entities that we own
-// This works because we only predict the user's controlled entity.
-// If we were predicting more entities, we would have to only apply movement to the player owned one.
+/// A confirmed entity's place within its group: which group it belongs to, and the
+/// predicted/interpolated entity currently standing in for it.
+struct GroupMember {
+    group: GroupId,
+    replicated: Entity,
+}
+
+/// Confirmed↔replicated mapping and per-group dependency graph, generic over the replicated
+/// role (`Predicted`/`Interpolated`) so it backs both `PredictionGroup` and `InterpolationGroup`.
+#[derive(Resource)]
+pub(crate) struct ReplicationGroup<Role> {
+    /// confirmed entity -> its group and replicated counterpart.
+    members: bevy::utils::HashMap<Entity, GroupMember>,
+    /// group id -> confirmed entities in that group, in the order they were registered; no
+    /// dependency relation between members is recorded or used to order them.
+    dependency_graph: bevy::utils::HashMap<u64, Vec<Entity>>,
+    _role: std::marker::PhantomData<Role>,
+}
+
+impl<Role> Default for ReplicationGroup<Role> {
+    fn default() -> Self {
+        Self {
+            members: default(),
+            dependency_graph: default(),
+            _role: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<Role> ReplicationGroup<Role> {
+    /// Record that `confirmed` (in `group`) is now replicated by `replicated`.
+    pub(crate) fn register(&mut self, group: GroupId, confirmed: Entity, replicated: Entity) {
+        self.members
+            .insert(confirmed, GroupMember { group, replicated });
+        let group_members = self.dependency_graph.entry(group.0).or_default();
+        if !group_members.contains(&confirmed) {
+            group_members.push(confirmed);
+        }
+    }
+
+    /// Drop `confirmed` once it stops being replicated, by either end of the mapping.
+    pub(crate) fn unregister_confirmed(&mut self, confirmed: Entity) {
+        if let Some(member) = self.members.remove(&confirmed) {
+            if let Some(group_members) = self.dependency_graph.get_mut(&member.group.0) {
+                group_members.retain(|entity| *entity != confirmed);
+            }
+        }
+    }
+
+    pub(crate) fn unregister_replicated(&mut self, replicated: Entity) {
+        if let Some(confirmed) = self
+            .members
+            .iter()
+            .find(|(_, member)| member.replicated == replicated)
+            .map(|(confirmed, _)| *confirmed)
+        {
+            self.unregister_confirmed(confirmed);
+        }
+    }
+
+    pub(crate) fn replicated_of(&self, confirmed: Entity) -> Option<Entity> {
+        self.members.get(&confirmed).map(|member| member.replicated)
+    }
+
+    /// Confirmed entities of `group`, in registration order.
+    pub(crate) fn dependency_order(&self, group: GroupId) -> &[Entity] {
+        self.dependency_graph
+            .get(&group.0)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    fn group_ids(&self) -> impl Iterator<Item = GroupId> + '_ {
+        self.dependency_graph.keys().copied().map(GroupId)
+    }
+}
+
+/// Confirmed↔predicted mapping/dependency graph; see `ReplicationGroup`.
+pub(crate) type PredictionGroup = ReplicationGroup<Predicted>;
+/// Confirmed↔interpolated mapping/dependency graph; see `ReplicationGroup`.
+pub(crate) type InterpolationGroup = ReplicationGroup<Interpolated>;
+
+// Maintain `PredictionGroup` as entities start/stop being predicted. `GroupId` lives on the
+// confirmed entity; `Predicted::confirmed_entity` is how we find it from the predicted side.
+fn track_prediction_group(
+    mut groups: ResMut<PredictionGroup>,
+    added: Query<(Entity, &Predicted), Added<Predicted>>,
+    confirmed_groups: Query<&GroupId>,
+    mut removed: RemovedComponents<Predicted>,
+) {
+    for (predicted_entity, predicted) in added.iter() {
+        let group_id = confirmed_groups
+            .get(predicted.confirmed_entity)
+            .copied()
+            .unwrap_or_else(|| GroupId::singleton(predicted.confirmed_entity));
+        groups.register(group_id, predicted.confirmed_entity, predicted_entity);
+    }
+    for predicted_entity in removed.read() {
+        groups.unregister_replicated(predicted_entity);
+    }
+}
+
+// Maintain `InterpolationGroup` as entities start/stop being interpolated, mirroring
+// `track_prediction_group` for `Interpolated` instead of `Predicted`.
+fn track_interpolation_group(
+    mut groups: ResMut<InterpolationGroup>,
+    added: Query<(Entity, &Interpolated), Added<Interpolated>>,
+    confirmed_groups: Query<&GroupId>,
+    mut removed: RemovedComponents<Interpolated>,
+) {
+    for (interpolated_entity, interpolated) in added.iter() {
+        let group_id = confirmed_groups
+            .get(interpolated.confirmed_entity)
+            .copied()
+            .unwrap_or_else(|| GroupId::singleton(interpolated.confirmed_entity));
+        groups.register(group_id, interpolated.confirmed_entity, interpolated_entity);
+    }
+    for interpolated_entity in removed.read() {
+        groups.unregister_replicated(interpolated_entity);
+    }
+}
+
+// Every entity in each prediction group's dependency graph is visited, but only the entity
+// owned by `metadata.client_id` receives local input; other members of the
+// group (e.g. objects being pushed) are predicted from replicated state instead.
 fn player_movement(
     mut position_query: Query<&mut PlayerPosition, With<Predicted>>,
+    confirmed_players: Query<&PlayerId, With<Confirmed>>,
+    metadata: Res<GlobalMetadata>,
+    groups: Res<PredictionGroup>,
     // InputEvent is a special case: we get an event for every fixed-update system run instead of every frame!
     mut input_reader: EventReader<InputEvent<Inputs>>,
 ) {
@@ -165,11 +601,25 @@ fn player_movement(
         return;
     }
     for input in input_reader.read() {
-        if let Some(input) = input.input() {
-            for position in position_query.iter_mut() {
-                // NOTE: be careful to directly pass Mut<PlayerPosition>
-                // getting a mutable reference triggers change detection, unless you use `as_deref_mut()`
-                shared_movement_behaviour(position, input);
+        let Some(input) = input.input() else {
+            continue;
+        };
+        for group_id in groups.group_ids() {
+            for &confirmed in groups.dependency_order(group_id) {
+                let Some(predicted) = groups.replicated_of(confirmed) else {
+                    continue;
+                };
+                let Ok(position) = position_query.get_mut(predicted) else {
+                    continue;
+                };
+                let owned_by_us = confirmed_players
+                    .get(confirmed)
+                    .is_ok_and(|player_id| Some(player_id.0) == metadata.client_id);
+                if owned_by_us {
+                    // NOTE: be careful to directly pass Mut<PlayerPosition>
+                    // getting a mutable reference triggers change detection, unless you use `as_deref_mut()`
+                    shared_movement_behaviour(position, input.direction);
+                }
             }
         }
     }
@@ -195,18 +645,15 @@ fn spawn_player(
     }
     for input in input_reader.read() {
         if let Some(input) = input.input() {
-            match input {
-                Inputs::Spawn => {
-                    debug!("got spawn input");
-                    commands.spawn((
-                        PlayerBundle::new(client_id, Vec2::ZERO, color_from_id(client_id)),
-                        // IMPORTANT: this lets the server know that the entity is pre-predicted
-                        // when the server replicates this entity; we will get a Confirmed entity which will use this entity
-                        // as the Predicted version
-                        ShouldBePredicted::default(),
-                    ));
-                }
-                _ => {}
+            if input.actions.contains(InputActions::SPAWN) {
+                debug!("got spawn input");
+                commands.spawn((
+                    PlayerBundle::new(client_id, Vec2::ZERO, color_from_id(client_id)),
+                    // IMPORTANT: this lets the server know that the entity is pre-predicted
+                    // when the server replicates this entity; we will get a Confirmed entity which will use this entity
+                    // as the Predicted version
+                    ShouldBePredicted::default(),
+                ));
             }
         }
     }
@@ -233,29 +680,114 @@ fn delete_player(
 
     for input in input_reader.read() {
         if let Some(input) = input.input() {
-            match input {
-                Inputs::Delete => {
-                    for (entity, player_id) in players.iter() {
-                        if player_id.0 == client_id {
-                            if let Some(mut entity_mut) = commands.get_entity(entity) {
-                                // we need to use this special function to despawn prediction entity
-                                // the reason is that we actually keep the entity around for a while,
-                                // in case we need to re-store it for rollback
-                                entity_mut.prediction_despawn::<MyProtocol>();
-                                debug!("Despawning the predicted/pre-predicted player because we received player action!");
-                            }
+            if input.actions.contains(InputActions::DELETE) {
+                for (entity, player_id) in players.iter() {
+                    if player_id.0 == client_id {
+                        if let Some(mut entity_mut) = commands.get_entity(entity) {
+                            // we need to use this special function to despawn prediction entity
+                            // the reason is that we actually keep the entity around for a while,
+                            // in case we need to re-store it for rollback
+                            entity_mut.prediction_despawn::<MyProtocol>();
+                            debug!("Despawning the predicted/pre-predicted player because we received player action!");
                         }
                     }
                 }
-                _ => {}
             }
         }
     }
 }
 
-fn touch_event_system(mut touch_events: EventReader<TouchInput>) {
+// Track active touches inside the joystick region and turn the knob's offset from its
+// origin into a `Direction` that `buffer_input` consumes.
+fn touch_event_system(
+    mut touch_events: EventReader<TouchInput>,
+    mut touch_state: ResMut<TouchInputState>,
+    base_query: Query<(&GlobalTransform, &Node), (With<JoystickBase>, Without<JoystickKnob>)>,
+    mut knob_query: Query<&mut Style, With<JoystickKnob>>,
+) {
+    let Ok((base_transform, base_node)) = base_query.get_single() else {
+        return;
+    };
+    let origin = base_transform.translation().truncate();
+
     for event in touch_events.read() {
-        info!("{:?}", event);
+        match event.phase {
+            TouchPhase::Started => {
+                if touch_state.active_touch.is_some() {
+                    continue;
+                }
+                let half_extent = base_node.size() / 2.0;
+                let local = event.position - origin;
+                if local.x.abs() <= half_extent.x && local.y.abs() <= half_extent.y {
+                    touch_state.active_touch = Some((event.id, origin));
+                }
+            }
+            TouchPhase::Moved => {
+                let Some((active_id, active_origin)) = touch_state.active_touch else {
+                    continue;
+                };
+                if active_id != event.id {
+                    continue;
+                }
+                let mut delta = event.position - active_origin;
+                if delta.length() > JOYSTICK_RADIUS {
+                    delta = delta.normalize() * JOYSTICK_RADIUS;
+                }
+                if let Ok(mut knob_style) = knob_query.get_single_mut() {
+                    // `delta` is in touch/window space (y-down), same as `Style::top`, so the
+                    // knob should follow it directly instead of being flipped.
+                    knob_style.left = Val::Px(JOYSTICK_RADIUS * 0.5 + delta.x);
+                    knob_style.top = Val::Px(JOYSTICK_RADIUS * 0.5 + delta.y);
+                }
+                let normalized = delta / JOYSTICK_RADIUS;
+                // Touch/window space is y-down; flip to match the y-up convention
+                // `window_relative_mouse_position` already uses for direction/world space.
+                touch_state.direction = Direction {
+                    up: normalized.y < -JOYSTICK_DEAD_ZONE,
+                    down: normalized.y > JOYSTICK_DEAD_ZONE,
+                    left: normalized.x < -JOYSTICK_DEAD_ZONE,
+                    right: normalized.x > JOYSTICK_DEAD_ZONE,
+                };
+            }
+            TouchPhase::Ended | TouchPhase::Canceled => {
+                let Some((active_id, _)) = touch_state.active_touch else {
+                    continue;
+                };
+                if active_id != event.id {
+                    continue;
+                }
+                touch_state.active_touch = None;
+                touch_state.direction = Direction {
+                    up: false,
+                    down: false,
+                    left: false,
+                    right: false,
+                };
+                if let Ok(mut knob_style) = knob_query.get_single_mut() {
+                    knob_style.left = Val::Px(JOYSTICK_RADIUS * 0.5);
+                    knob_style.top = Val::Px(JOYSTICK_RADIUS * 0.5);
+                }
+            }
+        }
+    }
+}
+
+// Latch the spawn/delete action buttons so `buffer_input` can pick them up on the same
+// tick as keyboard/joystick input.
+fn touch_action_buttons(
+    mut touch_state: ResMut<TouchInputState>,
+    spawn_query: Query<&Interaction, (Changed<Interaction>, With<TouchSpawnButton>)>,
+    delete_query: Query<&Interaction, (Changed<Interaction>, With<TouchDeleteButton>)>,
+) {
+    for interaction in spawn_query.iter() {
+        if *interaction == Interaction::Pressed {
+            touch_state.spawn = true;
+        }
+    }
+    for interaction in delete_query.iter() {
+        if *interaction == Interaction::Pressed {
+            touch_state.delete = true;
+        }
     }
 }
 
@@ -305,13 +837,21 @@ pub(crate) fn receive_message(mut reader: EventReader<MessageEvent<Message1>>) {
     }
 }
 
-/// Send messages from server to clients
+/// Send a message to all clients (re-broadcast by the server); queues via `PendingOutbox` until `client_id` syncs.
 pub(crate) fn send_message(
     mut client: ResMut<ClientConnectionManager>,
     input: Res<ButtonInput<KeyCode>>,
+    metadata: Res<GlobalMetadata>,
+    mut outbox: ResMut<PendingOutbox>,
 ) {
     if input.pressed(KeyCode::KeyM) {
         let message = Message1(5);
+        if metadata.client_id.is_none() {
+            // Only the latest queued value per kind is kept, so holding KeyM doesn't
+            // balloon the queue while we wait to sync.
+            outbox.updates.insert(OutboxUpdateKind::Message1, message);
+            return;
+        }
         info!("Send message: {:?}", message);
         // the message will be re-broadcasted by the server to all clients
         client
@@ -340,4 +880,77 @@ pub(crate) fn handle_interpolated_spawn(
     for mut color in interpolated.iter_mut() {
         color.0.set_s(0.1);
     }
+}
+
+/// A kind of outgoing send that `PendingOutbox` dedupes on, so only the latest value queued
+/// for that kind survives a long pre-sync wait.
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+pub(crate) enum OutboxUpdateKind {
+    Message1,
+}
+
+/// Delay between retrying a drained send that failed, so draining the outbox can't spin in a
+/// tight loop against a connection that still isn't ready.
+const OUTBOX_RETRY_DELAY: Duration = Duration::from_millis(200);
+
+/// Outgoing sends queued while `GlobalMetadata::client_id` hasn't synced yet; `updates` keep
+/// only the latest value per kind, `actions` preserve spawn/delete input in queued order.
+#[derive(Resource, Default)]
+pub(crate) struct PendingOutbox {
+    updates: bevy::utils::HashMap<OutboxUpdateKind, Message1>,
+    actions: std::collections::VecDeque<InputActions>,
+    retry: Option<Timer>,
+}
+
+// Once `GlobalMetadata::client_id` syncs, drain anything queued in `PendingOutbox`: the
+// latest update per kind, then queued actions in order. A failed send is put back and
+// retried after `OUTBOX_RETRY_DELAY` rather than spinning every frame.
+fn drain_pending_outbox(
+    metadata: Res<GlobalMetadata>,
+    mut outbox: ResMut<PendingOutbox>,
+    mut client: ResMut<ClientConnectionManager>,
+    tick_manager: Res<TickManager>,
+    time: Res<Time>,
+    mut last_replay_tick: Local<Option<Tick>>,
+) {
+    if metadata.client_id.is_none() {
+        return;
+    }
+    if let Some(retry) = outbox.retry.as_mut() {
+        if !retry.tick(time.delta()).just_finished() {
+            return;
+        }
+        outbox.retry = None;
+    }
+
+    if let Some(message) = outbox.updates.remove(&OutboxUpdateKind::Message1) {
+        info!("Send queued message: {:?}", message);
+        if let Err(e) =
+            client.send_message_to_target::<Channel1, Message1>(message, NetworkTarget::All)
+        {
+            error!("Failed to send queued message: {:?}", e);
+            outbox.updates.insert(OutboxUpdateKind::Message1, message);
+            outbox.retry = Some(Timer::new(OUTBOX_RETRY_DELAY, TimerMode::Once));
+            return;
+        }
+    }
+
+    // `add_input` writes the per-tick input buffer for the current tick, same as
+    // `buffer_input` — replaying the whole queue against one `tick` would have each
+    // queued action overwrite the last. Replay a single action per tick instead, so a
+    // queued spawn isn't clobbered by a queued delete before either reaches the server.
+    let tick = tick_manager.tick();
+    if *last_replay_tick == Some(tick) {
+        return;
+    }
+    if let Some(actions) = outbox.actions.pop_front() {
+        *last_replay_tick = Some(tick);
+        client.add_input(
+            Inputs {
+                direction: Direction::default(),
+                actions,
+            },
+            tick,
+        );
+    }
 }
\ No newline at end of file
@@ -0,0 +1,83 @@
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+
+use bevy::prelude::*;
+pub use lightyear::prelude::client::*;
+use lightyear::prelude::*;
+
+mod client;
+mod protocol;
+
+use client::{ClientPluginGroup, SteamConfig};
+
+const SERVER_ADDR: SocketAddr = SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 5000));
+const CLIENT_ADDR: SocketAddr = SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::new(0, 0, 0, 0), 0));
+
+/// Which transport the client connects over, chosen at launch.
+pub enum ClientTransports {
+    Udp,
+    WebTransport,
+    Steam(SteamConfig),
+}
+
+/// Settings shared between the example's client and server binaries.
+pub struct SharedSettings {
+    pub server_port: u16,
+}
+
+// Build the `ClientPluginGroup` for the selected transport. Only `Steam` produces
+// `SteamworksHandles`; those have to be registered as non-send resources by the caller (see
+// `ClientPluginGroup::new_steam`'s doc comment for why).
+fn build_client_plugin_group(
+    transport: ClientTransports,
+) -> (ClientPluginGroup, Option<client::SteamworksHandles>) {
+    match transport {
+        ClientTransports::Steam(steam_config) => {
+            let (group, handles) = ClientPluginGroup::new_steam(steam_config);
+            (group, Some(handles))
+        }
+        ClientTransports::Udp => {
+            let net_config = NetConfig::Netcode {
+                auth: Authentication::Manual {
+                    server_addr: SERVER_ADDR,
+                    client_id: rand::random(),
+                    private_key: Key::default(),
+                    protocol_id: 0,
+                },
+                config: NetcodeConfig::default(),
+                io: IoConfig::from_transport(TransportConfig::UdpSocket(CLIENT_ADDR)),
+            };
+            (ClientPluginGroup::new(net_config), None)
+        }
+        ClientTransports::WebTransport => {
+            let net_config = NetConfig::Netcode {
+                auth: Authentication::Manual {
+                    server_addr: SERVER_ADDR,
+                    client_id: rand::random(),
+                    private_key: Key::default(),
+                    protocol_id: 0,
+                },
+                config: NetcodeConfig::default(),
+                io: IoConfig::from_transport(TransportConfig::WebTransportClient {
+                    client_addr: CLIENT_ADDR,
+                    server_addr: SERVER_ADDR,
+                }),
+            };
+            (ClientPluginGroup::new(net_config), None)
+        }
+    }
+}
+
+fn main() {
+    // TODO: read this from CLI args instead of hardcoding it.
+    let transport = ClientTransports::Steam(SteamConfig::default());
+    let (group, steam_handles) = build_client_plugin_group(transport);
+
+    let mut app = App::new();
+    app.add_plugins(DefaultPlugins);
+    app.add_plugins(group);
+    if let Some(handles) = steam_handles {
+        app.insert_non_send_resource(handles.client);
+        app.insert_non_send_resource(handles.single);
+    }
+    app.run();
+}
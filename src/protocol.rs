@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+use lightyear::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A movement direction as four independent bools, so opposite keys held together cancel out
+/// naturally instead of needing to be disallowed.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Direction {
+    pub up: bool,
+    pub down: bool,
+    pub left: bool,
+    pub right: bool,
+}
+
+impl Direction {
+    pub fn is_none(&self) -> bool {
+        !self.up && !self.down && !self.left && !self.right
+    }
+}
+
+bitflags::bitflags! {
+    /// Action-style inputs (as opposed to `Direction`'s continuous movement), combined into a
+    /// single `Inputs` so spawn/delete can coexist with movement on the same tick.
+    #[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub struct InputActions: u8 {
+        const SPAWN = 1 << 0;
+        const DELETE = 1 << 1;
+    }
+}
+
+/// The input buffered once per tick: a `Direction` plus whichever `InputActions` were pressed,
+/// so movement and spawn/delete no longer compete for a single enum variant.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct Inputs {
+    pub direction: Direction,
+    pub actions: InputActions,
+}
+
+impl UserAction for Inputs {}